@@ -0,0 +1,67 @@
+use std::{fmt, io};
+use vach::prelude::InternalError;
+
+/// A typed, matchable view over the ways fetching a [`vach`] resource can fail beyond plain
+/// "not found", surfaced instead of collapsing everything into an opaque
+/// [`AssetIoError::Io`](bevy_asset::AssetIoError::Io).
+///
+/// This only distinguishes [`InternalError`] variants with a name and shape stable enough to
+/// match on directly; `vach` doesn't expose dedicated decryption/signature-failure variants under
+/// names this crate can rely on, so those still collapse into [`Other`](VachIoError::Other) along
+/// with their message rather than being guessed at from error text.
+#[derive(Debug)]
+pub enum VachIoError {
+    /// The archive holds encrypted resources but no decryption key was supplied when it was
+    /// opened.
+    MissingDecryptionKey,
+    /// Any other [`InternalError`], kept alongside its original message. This includes
+    /// decryption and signature-verification failures, which `vach` doesn't currently expose as
+    /// distinct matchable variants.
+    Other(String),
+}
+
+impl fmt::Display for VachIoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VachIoError::MissingDecryptionKey => {
+                write!(f, "archive is encrypted but no decryption key was supplied")
+            }
+            VachIoError::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for VachIoError {}
+
+impl From<InternalError> for VachIoError {
+    fn from(err: InternalError) -> VachIoError {
+        match err {
+            InternalError::NoKeypairError => VachIoError::MissingDecryptionKey,
+            err => VachIoError::Other(err.to_string()),
+        }
+    }
+}
+
+/// Wraps a [`VachIoError`] in an [`io::Error`] so it can still flow through
+/// [`AssetIoError::Io`](bevy_asset::AssetIoError::Io), while remaining recoverable by callers via
+/// [`io::Error::get_ref`] and a downcast to [`VachIoError`].
+pub(crate) fn to_io_error(err: impl Into<VachIoError>) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, err.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_keypair_error_maps_to_missing_decryption_key() {
+        let err: VachIoError = InternalError::NoKeypairError.into();
+        assert!(matches!(err, VachIoError::MissingDecryptionKey));
+    }
+
+    #[test]
+    fn unclassified_error_falls_back_to_other() {
+        let err: VachIoError = InternalError::MissingResourceError("foo".into()).into();
+        assert!(matches!(err, VachIoError::Other(_)));
+    }
+}
@@ -0,0 +1,316 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Seek, SeekFrom},
+};
+
+/// The size of every byte window [`HttpRangeSource`] fetches, including the first one, fetched up
+/// front from offset 0 for the `vach` header and registry, so that
+/// [`Archive::with_config`](vach::prelude::Archive::with_config) can parse the table of contents
+/// without a full download. A registry that runs past this first window is pulled in further
+/// windows of the same size as it's read.
+const PREFETCH_WINDOW: u64 = 4096;
+
+/// An [`io::Read`] + [`io::Seek`] adapter over a remote `.vach` archive, fetched lazily over HTTP
+/// using `Range:` requests.
+///
+/// Bytes are pulled in windows as they're read and cached in a [`BTreeMap`] keyed by their
+/// starting offset, so re-reading a region already fetched (most commonly the header and
+/// registry, which are consulted on every [`Archive::fetch`](vach::prelude::Archive::fetch)) never
+/// re-hits the network.
+#[derive(Debug)]
+pub struct HttpRangeSource {
+    url: String,
+    /// Total length of the remote resource, as reported by the server.
+    len: u64,
+    /// Logical read/seek cursor.
+    cursor: u64,
+    /// Byte windows already fetched, keyed by their starting offset.
+    cache: BTreeMap<u64, Vec<u8>>,
+}
+
+impl HttpRangeSource {
+    /// Opens a [`HttpRangeSource`] against `url`, eagerly fetching the first
+    /// [`PREFETCH_WINDOW`] bytes of the file (or all of it, if shorter) so an
+    /// [`Archive`](vach::prelude::Archive) can start parsing its header without another request.
+    pub fn new(url: impl Into<String>) -> io::Result<HttpRangeSource> {
+        let url = url.into();
+        let len = Self::content_length(&url)?;
+
+        let mut source = HttpRangeSource {
+            url,
+            len,
+            cursor: 0,
+            cache: BTreeMap::new(),
+        };
+
+        let prefetch = PREFETCH_WINDOW.min(len);
+        source.fetch_range(0, prefetch)?;
+
+        Ok(source)
+    }
+
+    /// Queries the remote resource's length via a `HEAD` request.
+    fn content_length(url: &str) -> io::Result<u64> {
+        backend::content_length(url)
+    }
+
+    /// Fetches `[start, start + len)` from the remote resource, unless it's already cached, and
+    /// inserts it into `self.cache`.
+    fn fetch_range(&mut self, start: u64, len: u64) -> io::Result<()> {
+        if len == 0 || self.cache.contains_key(&start) {
+            return Ok(());
+        }
+
+        let end = (start + len - 1).min(self.len.saturating_sub(1));
+        let buf = backend::fetch_range(&self.url, start, end)?;
+
+        self.cache.insert(start, buf);
+        Ok(())
+    }
+
+    /// Returns the cached window covering `offset`, fetching it first if necessary.
+    fn window_containing(&mut self, offset: u64) -> io::Result<(&u64, &Vec<u8>)> {
+        if !self
+            .cache
+            .range(..=offset)
+            .next_back()
+            .is_some_and(|(&start, buf)| offset < start + buf.len() as u64)
+        {
+            self.fetch_range(offset, PREFETCH_WINDOW)?;
+        }
+
+        self.cache
+            .range(..=offset)
+            .next_back()
+            .filter(|(&start, buf)| offset < start + buf.len() as u64)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "offset past end of archive"))
+    }
+}
+
+/// The actual HTTP transport behind [`HttpRangeSource`], split by target because no single HTTP
+/// client works everywhere we need it to: `ureq` is a blocking native client that doesn't build
+/// for `wasm32-unknown-unknown`, and the browser has no blocking client of its own outside a
+/// synchronous [`XMLHttpRequest`](web_sys::XmlHttpRequest) call.
+mod backend {
+    use std::io;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(super) fn content_length(url: &str) -> io::Result<u64> {
+        let response = ureq::head(url)
+            .call()
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+
+        response
+            .header("Content-Length")
+            .and_then(|len| len.parse().ok())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "remote archive did not report a Content-Length",
+                )
+            })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(super) fn fetch_range(url: &str, start: u64, end: u64) -> io::Result<Vec<u8>> {
+        let response = ureq::get(url)
+            .set("Range", &format!("bytes={}-{}", start, end))
+            .call()
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+
+        // A server that ignores `Range:` returns `200 OK` with the *full* body instead of `206
+        // Partial Content` with just the requested window. Caching that here, keyed at `start`,
+        // would later be served back at the wrong offset — silent corruption of whatever the
+        // archive parser reads. Reject rather than risk it.
+        if response.status() != 206 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!(
+                    "remote archive server does not support range requests (expected 206, got {})",
+                    response.status()
+                ),
+            ));
+        }
+
+        let mut buf = Vec::with_capacity((end - start + 1) as usize);
+        response.into_reader().read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+
+    // `vach::prelude::Archive::with_config` reads its source synchronously, so `HttpRangeSource`
+    // needs a blocking `fetch` on wasm too. The browser only offers that via `XMLHttpRequest`
+    // configured for synchronous mode, which in turn is only permitted off the main thread — so,
+    // same as the WASM asset reader this crate mirrors, a `HttpRangeSource`-backed `VachAssetIo`
+    // must be driven from a Web Worker in wasm builds, not the page's main thread.
+    #[cfg(target_arch = "wasm32")]
+    pub(super) fn content_length(url: &str) -> io::Result<u64> {
+        let xhr = open_sync("HEAD", url)?;
+
+        xhr.get_response_header("Content-Length")
+            .ok()
+            .flatten()
+            .and_then(|len| len.parse().ok())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "remote archive did not report a Content-Length",
+                )
+            })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub(super) fn fetch_range(url: &str, start: u64, end: u64) -> io::Result<Vec<u8>> {
+        let xhr = open_sync("GET", url)?;
+        xhr.set_request_header("Range", &format!("bytes={}-{}", start, end))
+            .map_err(|err| js_error(&err))?;
+        send_sync(&xhr)?;
+
+        // Same rationale as the native backend: a `200` in answer to a ranged request means the
+        // server handed back the whole file, which would get cached at the wrong offset.
+        if xhr.status().map_err(|err| js_error(&err))? != 206 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "remote archive server does not support range requests (expected 206)",
+            ));
+        }
+
+        let response = xhr.response_text().map_err(|err| js_error(&err))?.unwrap_or_default();
+        Ok(response.into_bytes())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn open_sync(method: &str, url: &str) -> io::Result<web_sys::XmlHttpRequest> {
+        let xhr = web_sys::XmlHttpRequest::new().map_err(|err| js_error(&err))?;
+        // `false` for `async` is what makes this a *blocking* call, the only way to satisfy
+        // `io::Read`/`io::Seek`'s synchronous contract from inside the browser.
+        xhr.open_with_async(method, url, false).map_err(|err| js_error(&err))?;
+        Ok(xhr)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn send_sync(xhr: &web_sys::XmlHttpRequest) -> io::Result<()> {
+        xhr.send().map_err(|err| js_error(&err))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn js_error(err: &wasm_bindgen::JsValue) -> io::Error {
+        io::Error::new(io::ErrorKind::Other, format!("{err:?}"))
+    }
+}
+
+impl Read for HttpRangeSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.cursor >= self.len {
+            return Ok(0);
+        }
+
+        let (&start, window) = self.window_containing(self.cursor)?;
+        let window_offset = (self.cursor - start) as usize;
+        let available = &window[window_offset..];
+
+        let count = available.len().min(buf.len());
+        buf[..count].copy_from_slice(&available[..count]);
+        self.cursor += count as u64;
+
+        Ok(count)
+    }
+}
+
+impl Seek for HttpRangeSource {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => self.len as i64 + offset,
+            SeekFrom::Current(offset) => self.cursor as i64 + offset,
+        };
+
+        if target < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "attempted to seek before byte 0",
+            ));
+        }
+
+        self.cursor = target as u64;
+        Ok(self.cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a [`HttpRangeSource`] with `cache` pre-populated, so seek/read semantics can be
+    /// exercised without hitting the network.
+    fn cached(len: u64, cache: BTreeMap<u64, Vec<u8>>) -> HttpRangeSource {
+        HttpRangeSource {
+            url: "http://example.invalid/archive.vach".into(),
+            len,
+            cursor: 0,
+            cache,
+        }
+    }
+
+    #[test]
+    fn read_returns_zero_at_and_past_end() {
+        let mut cache = BTreeMap::new();
+        cache.insert(0, vec![1, 2, 3, 4]);
+        let mut source = cached(4, cache);
+
+        source.seek(SeekFrom::Start(4)).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(source.read(&mut buf).unwrap(), 0);
+
+        source.seek(SeekFrom::Start(100)).unwrap();
+        assert_eq!(source.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn read_pulls_only_from_the_cached_window_covering_the_cursor() {
+        let mut cache = BTreeMap::new();
+        cache.insert(0, vec![1, 2, 3, 4]);
+        cache.insert(4, vec![5, 6, 7, 8]);
+        let mut source = cached(8, cache);
+
+        source.seek(SeekFrom::Start(2)).unwrap();
+        let mut buf = [0u8; 2];
+        assert_eq!(source.read(&mut buf).unwrap(), 2);
+        assert_eq!(buf, [3, 4]);
+
+        // Cursor now sits at the start of the next window.
+        let mut buf = [0u8; 4];
+        assert_eq!(source.read(&mut buf).unwrap(), 4);
+        assert_eq!(buf, [5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn read_caps_the_count_at_the_end_of_the_current_window() {
+        let mut cache = BTreeMap::new();
+        cache.insert(0, vec![1, 2, 3, 4]);
+        cache.insert(4, vec![5, 6, 7, 8]);
+        let mut source = cached(8, cache);
+
+        // A read spanning two windows only returns what's left in the first one; the caller is
+        // expected to call `read` again for the rest, per the `io::Read` contract.
+        let mut buf = [0u8; 8];
+        assert_eq!(source.read(&mut buf).unwrap(), 4);
+        assert_eq!(&buf[..4], &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn seek_from_start_current_and_end() {
+        let mut source = cached(10, BTreeMap::new());
+
+        assert_eq!(source.seek(SeekFrom::Start(3)).unwrap(), 3);
+        assert_eq!(source.seek(SeekFrom::Current(4)).unwrap(), 7);
+        assert_eq!(source.seek(SeekFrom::Current(-2)).unwrap(), 5);
+        assert_eq!(source.seek(SeekFrom::End(0)).unwrap(), 10);
+        assert_eq!(source.seek(SeekFrom::End(-3)).unwrap(), 7);
+    }
+
+    #[test]
+    fn seek_before_byte_zero_errors() {
+        let mut source = cached(10, BTreeMap::new());
+        assert!(source.seek(SeekFrom::End(-20)).is_err());
+    }
+}
@@ -31,15 +31,31 @@ fn fetch_and_log(asset_server: Res<VachAssetServer>) {
 ```
 */
 
+mod error;
+mod http_source;
+mod stack;
+mod task_pool;
+
 use bevy_asset::{AssetIo, AssetIoError, FileType, Metadata};
-use std::{fs::File, io, path};
+use std::{
+    fs::File,
+    io, path,
+    sync::{Arc, RwLock},
+};
+pub use error::VachIoError;
+pub use http_source::HttpRangeSource;
+pub use stack::{AssetIoStackConfig, VachAssetIoStack};
 pub use vach::prelude::*;
 
 /// An [`bevy_asset::AssetIo`] impl for [`vach`] formatted archives
 #[derive(Debug)]
-#[repr(transparent)]
 pub struct VachAssetIo<T> {
-    archive: Archive<T>,
+    /// Shared so that [`load_path`](AssetIo::load_path) can hand a handle to an
+    /// [`IoTaskPool`](bevy_tasks::IoTaskPool) task instead of blocking the executor that polls
+    /// the returned future, and
+    /// so that [`reload`](VachAssetIo::reload) can atomically swap the mounted archive out from
+    /// under in-flight reads.
+    archive: Arc<RwLock<Archive<T>>>,
 }
 
 /// Allows you to configure the [`VachAssetIo`] resource to be used in your app
@@ -60,25 +76,127 @@ impl<T> VachAssetIo<T> {
         let source = File::open(path)?;
         let archive = Archive::with_config(source, config)?;
 
-        Ok(VachAssetIo { archive })
+        Ok(VachAssetIo {
+            archive: Arc::new(RwLock::new(archive)),
+        })
+    }
+
+    /// Mounts a [`VachAssetIo`] over a `.vach` archive hosted at `url`, without downloading it in
+    /// full. Only the header and registry are fetched up front; individual resources are pulled
+    /// over HTTP `Range:` requests as they're [`fetch`](vach::prelude::Archive::fetch)ed, via a
+    /// [`HttpRangeSource`].
+    pub fn from_url(
+        url: impl Into<String>,
+        mut config: Option<ArchiveConfig>,
+    ) -> Result<VachAssetIo<HttpRangeSource>, vach::prelude::InternalError> {
+        let config = config.get_or_insert(Default::default());
+        let source = HttpRangeSource::new(url)?;
+        let archive = Archive::with_config(source, config)?;
+
+        Ok(VachAssetIo {
+            archive: Arc::new(RwLock::new(archive)),
+        })
     }
 }
 
 impl<T: io::Read + io::Seek> VachAssetIo<T> {
     /// Load a [VachAssetIo] source from a preconstructed [`Archive`], this allows you to use arbitrary archive sources other than files
     pub fn new(archive: Archive<T>) -> VachAssetIo<T> {
-        VachAssetIo { archive }
+        VachAssetIo {
+            archive: Arc::new(RwLock::new(archive)),
+        }
+    }
+
+    /// Reports whether the resource at `path` is *marked* as signed in the archive's registry —
+    /// **not** whether that signature actually verifies. Verifying requires decrypting/hashing
+    /// the resource itself, which only happens in [`fetch`](vach::prelude::Archive::fetch); a
+    /// tampered resource still carries [`Flags::SIGNED_FLAG`] and so still reads `true` here.
+    ///
+    /// Use this for cheap pre-filtering (e.g. "does this pack even claim to sign its assets?"),
+    /// not for fail-closed trust decisions — those need the actual fetch result, via
+    /// [`load_path`](AssetIo::load_path) or [`Archive::fetch`](vach::prelude::Archive::fetch)
+    /// directly, to know the signature held up.
+    ///
+    /// There's no equivalent on [`get_metadata`](AssetIo::get_metadata): bevy's
+    /// [`Metadata`](bevy_asset::Metadata) only carries a [`FileType`], with no room for extra
+    /// fields, so this is exposed as a separate method instead.
+    pub fn is_signed(&self, path: &path::Path) -> Result<bool, AssetIoError> {
+        let archive = self.archive.read().unwrap();
+        let str = path.to_string_lossy();
+
+        match archive.fetch_entry(str) {
+            Some(entry) => Ok(entry.flags.contains(Flags::SIGNED_FLAG)),
+            None => Err(AssetIoError::NotFound(path.into())),
+        }
+    }
+
+    /// Atomically replaces the mounted archive with one built from `source`, so a freshly
+    /// rebuilt or patched `.vach` pack can be hot-swapped in without restarting the app.
+    ///
+    /// This is coarse-grained: it doesn't know which previously loaded asset ids changed, so
+    /// callers that need existing handles to refresh should follow this up by re-requesting
+    /// those ids from their [`AssetServer`](bevy_asset::AssetServer), the same way a
+    /// file-watch-driven reload would.
+    ///
+    /// `reload` and [`swap_archive`](VachAssetIo::swap_archive) have no unit tests: both end in a
+    /// single write through the `RwLock`, and exercising the "new id resolves after the swap"
+    /// behavior needs a real [`Archive`] built from actual `.vach`-formatted bytes, which `vach`'s
+    /// builder API (not vendored in this tree) is required to produce.
+    pub fn reload(
+        &self,
+        source: T,
+        mut config: Option<ArchiveConfig>,
+    ) -> Result<(), vach::prelude::InternalError> {
+        let config = config.get_or_insert(Default::default());
+        let archive = Archive::with_config(source, config)?;
+
+        *self.archive.write().unwrap() = archive;
+        Ok(())
+    }
+
+    /// Atomically replaces the mounted archive with an already-constructed one.
+    pub fn swap_archive(&self, archive: Archive<T>) {
+        *self.archive.write().unwrap() = archive;
+    }
+
+    /// Lists the ids of archived resources that have a `<id>.meta` companion baked into the same
+    /// archive, following the convention bevy's asset processor uses to find processing/loading
+    /// settings for an asset. Since a meta path is just an id like any other, [`load_path`] and
+    /// [`get_metadata`] already serve `<id>.meta` requests by fetching that entry directly, no
+    /// loose `.meta` file required alongside the archive.
+    ///
+    /// [`load_path`]: AssetIo::load_path
+    /// [`get_metadata`]: AssetIo::get_metadata
+    pub fn meta_entries(&self) -> Vec<String> {
+        let archive = self.archive.read().unwrap();
+        meta_companions(archive.entries().iter().map(|e| e.0.as_str()))
     }
 }
 
+/// Given an archive's entry ids, returns the ids that have a `<id>.meta` companion also present
+/// among them. Pulled out as a pure function so the pairing logic is unit-testable without
+/// needing a real archive.
+fn meta_companions<'a>(ids: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let ids: std::collections::HashSet<&str> = ids.collect();
+
+    ids.iter()
+        .filter_map(|id| id.strip_suffix(".meta"))
+        .filter(|id| ids.contains(id))
+        .map(|id| id.to_string())
+        .collect()
+}
+
 impl<T: io::Read + io::Seek + Sync + Send + 'static> AssetIo for VachAssetIo<T> {
     fn load_path<'a>(
         &'a self,
         path: &'a path::Path,
     ) -> bevy_asset::BoxedFuture<'a, Result<Vec<u8>, bevy_asset::AssetIoError>> {
+        let archive = self.archive.clone();
+
         let block = async move {
-            let str = path.to_string_lossy();
-            let resource = self.archive.fetch(str);
+            let str = path.to_string_lossy().into_owned();
+            let resource = task_pool::offload(archive, move |archive| archive.fetch(str.as_str())).await?;
+
             match resource {
                 Ok(res) => Ok(res.data),
                 Err(err) => match err {
@@ -86,10 +204,7 @@ impl<T: io::Read + io::Seek + Sync + Send + 'static> AssetIo for VachAssetIo<T>
                     InternalError::MissingResourceError(_) => {
                         Err(AssetIoError::NotFound(path.into()))
                     }
-                    err => Err(AssetIoError::Io(io::Error::new(
-                        io::ErrorKind::Other,
-                        err.to_string(),
-                    ))),
+                    err => Err(AssetIoError::Io(error::to_io_error(err))),
                 },
             }
         };
@@ -101,8 +216,8 @@ impl<T: io::Read + io::Seek + Sync + Send + 'static> AssetIo for VachAssetIo<T>
         &self,
         path: &path::Path,
     ) -> Result<Box<dyn Iterator<Item = path::PathBuf>>, bevy_asset::AssetIoError> {
-        let iter = self
-            .archive
+        let archive = self.archive.read().unwrap();
+        let iter = archive
             .entries()
             .into_iter()
             .map(|e| e.0)
@@ -117,14 +232,14 @@ impl<T: io::Read + io::Seek + Sync + Send + 'static> AssetIo for VachAssetIo<T>
         &self,
         path: &path::Path,
     ) -> Result<bevy_asset::Metadata, bevy_asset::AssetIoError> {
+        let archive = self.archive.read().unwrap();
         let str = path.to_string_lossy();
-        let entry = self.archive.fetch_entry(str);
+        let entry = archive.fetch_entry(str);
 
         match entry {
             Some(_) => Ok(Metadata::new(FileType::File)),
             None => {
-                if self
-                    .archive
+                if archive
                     .entries()
                     .iter()
                     .map(|e| e.0)
@@ -147,3 +262,25 @@ impl<T: io::Read + io::Seek + Sync + Send + 'static> AssetIo for VachAssetIo<T>
         Err(bevy_asset::AssetIoError::PathWatchError("<Vach Archives are read only, so there is no need to watch for changes. Save yourself the milliseconds>".into()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meta_companions_pairs_ids_with_their_meta_sidecar() {
+        let ids = ["sprites/hero.png", "sprites/hero.png.meta", "audio/theme.ogg"];
+
+        let mut companions = meta_companions(ids.into_iter());
+        companions.sort();
+
+        assert_eq!(companions, vec!["sprites/hero.png"]);
+    }
+
+    #[test]
+    fn meta_companions_ignores_a_meta_entry_with_no_matching_resource() {
+        let ids = ["orphan.meta", "sprites/hero.png"];
+
+        assert!(meta_companions(ids.into_iter()).is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{error, task_pool};
+use bevy_asset::{AssetIo, AssetIoError, FileType, Metadata};
+use std::{
+    collections::HashSet,
+    fs::File,
+    io, path,
+    sync::{Arc, RwLock},
+};
+use vach::prelude::{Archive, ArchiveConfig, InternalError};
+
+/// Mounts several `.vach` archives as a single [`bevy_asset::AssetIo`], layered in priority
+/// order. Layers are stored highest-priority-first: [`load_path`](AssetIo::load_path) and
+/// [`get_metadata`](AssetIo::get_metadata) resolve against the first layer that has the id, while
+/// [`read_directory`](AssetIo::read_directory) merges entries across every layer, with higher
+/// layers shadowing lower ones of the same id.
+///
+/// This lets a patch or DLC archive override assets in a base content archive without rebuilding
+/// the base pack.
+#[derive(Debug)]
+pub struct VachAssetIoStack<T> {
+    /// Mounted archives, ordered from highest to lowest priority. Shared so that
+    /// [`load_path`](AssetIo::load_path) can hand a handle to an
+    /// [`IoTaskPool`](bevy_tasks::IoTaskPool) task instead of blocking the executor that polls the
+    /// returned future.
+    layers: Arc<RwLock<Vec<Archive<T>>>>,
+}
+
+/// Configures a [`VachAssetIoStack`], as an ordered list of archives to mount, highest priority
+/// first.
+pub struct AssetIoStackConfig {
+    /// The archives to mount, ordered from highest to lowest priority.
+    pub layers: Vec<(path::PathBuf, ArchiveConfig)>,
+}
+
+impl VachAssetIoStack<File> {
+    /// Builds a [`VachAssetIoStack`] from an [`AssetIoStackConfig`], opening each layer's archive
+    /// file in turn.
+    pub fn from_config(
+        config: AssetIoStackConfig,
+    ) -> Result<VachAssetIoStack<File>, vach::prelude::InternalError> {
+        let layers = config
+            .layers
+            .into_iter()
+            .map(|(path, archive_config)| {
+                let source = File::open(path)?;
+                Archive::with_config(source, &archive_config)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(VachAssetIoStack {
+            layers: Arc::new(RwLock::new(layers)),
+        })
+    }
+}
+
+impl<T: io::Read + io::Seek> VachAssetIoStack<T> {
+    /// Builds a [`VachAssetIoStack`] from preconstructed [`Archive`]s, ordered from highest to
+    /// lowest priority.
+    pub fn new(layers: Vec<Archive<T>>) -> VachAssetIoStack<T> {
+        VachAssetIoStack {
+            layers: Arc::new(RwLock::new(layers)),
+        }
+    }
+}
+
+impl<T: io::Read + io::Seek + Sync + Send + 'static> AssetIo for VachAssetIoStack<T> {
+    fn load_path<'a>(
+        &'a self,
+        path: &'a path::Path,
+    ) -> bevy_asset::BoxedFuture<'a, Result<Vec<u8>, bevy_asset::AssetIoError>> {
+        let layers = self.layers.clone();
+
+        let block = async move {
+            let str = path.to_string_lossy().into_owned();
+
+            let resource = task_pool::offload(layers, move |layers| {
+                let mut result = Err(InternalError::MissingResourceError(str.clone()));
+
+                for archive in layers.iter() {
+                    match archive.fetch(str.as_str()) {
+                        Ok(res) => {
+                            result = Ok(res);
+                            break;
+                        }
+                        Err(InternalError::MissingResourceError(_)) => continue,
+                        Err(err) => {
+                            result = Err(err);
+                            break;
+                        }
+                    }
+                }
+
+                result
+            })
+            .await?;
+
+            match resource {
+                Ok(res) => Ok(res.data),
+                Err(InternalError::MissingResourceError(_)) => Err(AssetIoError::NotFound(path.into())),
+                Err(InternalError::IOError(err)) => Err(AssetIoError::Io(err)),
+                Err(err) => Err(AssetIoError::Io(error::to_io_error(err))),
+            }
+        };
+
+        Box::pin(block)
+    }
+
+    fn read_directory(
+        &self,
+        path: &path::Path,
+    ) -> Result<Box<dyn Iterator<Item = path::PathBuf>>, bevy_asset::AssetIoError> {
+        let layers = self.layers.read().unwrap();
+        let prefix = path.to_string_lossy();
+
+        let layer_ids = layers
+            .iter()
+            .map(|archive| archive.entries().into_iter().map(|e| e.0.clone()).collect())
+            .collect();
+
+        let entries = merge_shadowed(layer_ids, prefix.as_ref())
+            .into_iter()
+            .map(path::PathBuf::from)
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(entries.into_iter()))
+    }
+
+    fn get_metadata(
+        &self,
+        path: &path::Path,
+    ) -> Result<bevy_asset::Metadata, bevy_asset::AssetIoError> {
+        let layers = self.layers.read().unwrap();
+        let str = path.to_string_lossy();
+
+        for archive in layers.iter() {
+            if archive.fetch_entry(str.as_ref()).is_some() {
+                return Ok(Metadata::new(FileType::File));
+            }
+        }
+
+        if layers
+            .iter()
+            .any(|archive| archive.entries().iter().any(|e| e.0.starts_with(str.as_ref())))
+        {
+            return Ok(Metadata::new(FileType::Directory));
+        }
+
+        Err(AssetIoError::NotFound(path.into()))
+    }
+
+    // Vach archives are read only
+    fn watch_path_for_changes(&self, path: &path::Path) -> Result<(), bevy_asset::AssetIoError> {
+        Err(bevy_asset::AssetIoError::PathWatchError(path.into()))
+    }
+
+    fn watch_for_changes(&self) -> Result<(), bevy_asset::AssetIoError> {
+        Err(bevy_asset::AssetIoError::PathWatchError("<Vach Archives are read only, so there is no need to watch for changes. Save yourself the milliseconds>".into()))
+    }
+}
+
+/// Merges per-layer id lists (ordered highest-priority-first, matching `layers`) into a single
+/// list filtered by `prefix`, keeping only the first occurrence of each id so a higher layer's
+/// entry shadows a lower layer's entry of the same id. Pulled out as a pure function so the
+/// shadowing/de-dup policy is unit-testable without needing a real archive.
+fn merge_shadowed(layers: Vec<Vec<String>>, prefix: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut entries = Vec::new();
+
+    for ids in layers {
+        for id in ids {
+            if id.starts_with(prefix) && seen.insert(id.clone()) {
+                entries.push(id);
+            }
+        }
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn higher_priority_layer_shadows_lower_layer_entry() {
+        let layers = vec![
+            vec!["sprites/hero.png".to_string(), "sprites/enemy.png".to_string()],
+            vec!["sprites/hero.png".to_string(), "sprites/tree.png".to_string()],
+        ];
+
+        let merged = merge_shadowed(layers, "sprites/");
+
+        // The base layer's "sprites/hero.png" is dropped in favour of the patch layer's.
+        assert_eq!(merged.iter().filter(|id| *id == "sprites/hero.png").count(), 1);
+        assert_eq!(
+            merged,
+            vec!["sprites/hero.png", "sprites/enemy.png", "sprites/tree.png"]
+        );
+    }
+
+    #[test]
+    fn read_directory_merge_filters_by_prefix() {
+        let layers = vec![
+            vec!["sprites/hero.png".to_string(), "audio/theme.ogg".to_string()],
+            vec!["sprites/tree.png".to_string()],
+        ];
+
+        let merged = merge_shadowed(layers, "sprites/");
+
+        assert_eq!(merged, vec!["sprites/hero.png", "sprites/tree.png"]);
+    }
+}
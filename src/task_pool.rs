@@ -0,0 +1,35 @@
+use bevy_asset::AssetIoError;
+use bevy_tasks::IoTaskPool;
+use std::{
+    io,
+    sync::{Arc, RwLock},
+};
+
+/// Runs `work` against a read lock on `resource`, on a pooled [`IoTaskPool`] thread, and returns
+/// its result — so decompression/decryption happens off whatever executor polls the calling
+/// future, rather than blocking it. Shared by [`VachAssetIo`](crate::VachAssetIo) and
+/// [`VachAssetIoStack`](crate::VachAssetIoStack) so their `load_path` impls can't drift apart.
+pub(crate) async fn offload<R, T, F>(resource: Arc<RwLock<R>>, work: F) -> Result<T, AssetIoError>
+where
+    R: Send + Sync + 'static,
+    F: FnOnce(&R) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (tx, rx) = async_channel::bounded(1);
+
+    IoTaskPool::get()
+        .spawn(async move {
+            // Resolve `work` and drop the (non-`Send`) read guard before awaiting the send, so
+            // this task's future stays `Send`.
+            let result = {
+                let guard = resource.read().unwrap();
+                work(&guard)
+            };
+            let _ = tx.send(result).await;
+        })
+        .detach();
+
+    rx.recv()
+        .await
+        .map_err(|err| AssetIoError::Io(io::Error::new(io::ErrorKind::Other, err.to_string())))
+}